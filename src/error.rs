@@ -4,4 +4,8 @@ use thiserror::Error;
 pub enum MapError {
     #[error("Position out of bounds")]
     OutOfBounds,
+    #[error("No entity registered for that name or tile id")]
+    UnknownEntity,
+    #[error("Could not parse tile id")]
+    InvalidTile,
 }
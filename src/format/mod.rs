@@ -0,0 +1,247 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entity::Entity,
+    error::MapError,
+    grid_room::GridRoom,
+    utils::{Position, Size, SINGLE_TILE},
+};
+
+/// An owned, serializable snapshot of a [`GridRoom`], with cells stored as
+/// row-major entity names instead of `&Entity` references so it can be
+/// saved to or loaded from a data file. `mobile_entities` and `footprints`
+/// are carried alongside the cell grid so `from_snapshot` can tell mobile
+/// entities (and their real footprints) apart from static ones instead of
+/// guessing from the grid alone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomSnapshot {
+    pub name: String,
+    pub size: Size,
+    pub cells: Vec<Option<String>>,
+    pub mobile_entities: BTreeMap<String, Position>,
+    pub footprints: BTreeMap<String, Vec<Position>>,
+}
+
+impl<'a> GridRoom<'a> {
+    /// Captures the room's current layout as a [`RoomSnapshot`].
+    pub fn to_snapshot(&self) -> RoomSnapshot {
+        let size = Size {
+            width: self.grid.cols(),
+            height: self.grid.rows(),
+        };
+
+        let mut cells = Vec::with_capacity(size.width * size.height);
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let name = self
+                    .grid
+                    .get(y, x)
+                    .and_then(|cell| *cell)
+                    .map(|entity| entity.name.clone());
+                cells.push(name);
+            }
+        }
+
+        RoomSnapshot {
+            name: self.name.clone(),
+            size,
+            cells,
+            mobile_entities: self.mobile_entities.clone(),
+            footprints: self.footprints.clone(),
+        }
+    }
+
+    /// Rebuilds a room from a [`RoomSnapshot`], rebinding each entity name
+    /// back to an `&'a Entity` via `entity_registry`. Mobile entities are
+    /// placed once each, using their recorded anchor position and
+    /// footprint, so multi-tile footprints and mobile/static status survive
+    /// the round trip instead of being re-derived per occupied cell.
+    pub fn from_snapshot(
+        snapshot: RoomSnapshot,
+        entity_registry: &BTreeMap<String, &'a Entity>,
+    ) -> Result<Self, MapError> {
+        let mut room = Self::new(snapshot.name, snapshot.size.clone());
+        let mut covered: HashSet<(usize, usize)> = HashSet::new();
+
+        for (name, position) in &snapshot.mobile_entities {
+            let entity = entity_registry.get(name).ok_or(MapError::UnknownEntity)?;
+            let footprint = snapshot
+                .footprints
+                .get(name)
+                .cloned()
+                .unwrap_or_else(|| SINGLE_TILE.to_vec());
+
+            for offset in &footprint {
+                covered.insert((position.x + offset.x, position.y + offset.y));
+            }
+
+            room.add_entity(entity, position.clone(), false, &footprint)?;
+        }
+
+        for (index, name) in snapshot.cells.into_iter().enumerate() {
+            let Some(name) = name else {
+                continue;
+            };
+
+            let position = Position {
+                x: index % snapshot.size.width,
+                y: index / snapshot.size.width,
+            };
+
+            if covered.contains(&(position.x, position.y)) {
+                continue;
+            }
+
+            let entity = entity_registry.get(&name).ok_or(MapError::UnknownEntity)?;
+            room.add_entity(entity, position, true, &SINGLE_TILE)?;
+        }
+
+        Ok(room)
+    }
+}
+
+/// Imports a Tiled-style CSV layer (one row per line, comma-separated tile
+/// ids) into a new `GridRoom`, placing a static entity wherever `tile_lookup`
+/// has an entry for that id and leaving id `0` (Tiled's "no tile" marker)
+/// blank.
+pub fn import_csv_layer<'a>(
+    name: String,
+    csv: &str,
+    tile_lookup: &BTreeMap<i32, &'a Entity>,
+) -> Result<GridRoom<'a>, MapError> {
+    let rows = csv
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.trim_end_matches(',')
+                .split(',')
+                .map(|value| value.trim().parse::<i32>().map_err(|_| MapError::InvalidTile))
+                .collect::<Result<Vec<i32>, MapError>>()
+        })
+        .collect::<Result<Vec<Vec<i32>>, MapError>>()?;
+
+    let height = rows.len();
+    let width = rows.first().map_or(0, Vec::len);
+
+    let mut room = GridRoom::new(name, Size { width, height });
+
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &tile_id) in row.iter().enumerate() {
+            if tile_id == 0 {
+                continue;
+            }
+
+            let entity = tile_lookup.get(&tile_id).ok_or(MapError::UnknownEntity)?;
+            room.add_entity(entity, Position { x, y }, true, &SINGLE_TILE)?;
+        }
+    }
+
+    Ok(room)
+}
+
+#[cfg(test)]
+mod format_tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        entity::Entity,
+        grid_room::GridRoom,
+        utils::{Position, Size, SINGLE_TILE},
+    };
+
+    use super::import_csv_layer;
+
+    #[test]
+    fn snapshot_round_trip() {
+        let wall = Entity {
+            name: "Wall".to_owned(),
+        };
+        let player = Entity {
+            name: "Player".to_owned(),
+        };
+
+        let mut room = GridRoom::new(
+            "Test".to_string(),
+            Size {
+                width: 3,
+                height: 2,
+            },
+        );
+        room.add_entity(&wall, Position { x: 1, y: 0 }, true, &SINGLE_TILE)
+            .unwrap();
+        room.add_entity(&player, Position { x: 0, y: 1 }, false, &SINGLE_TILE)
+            .unwrap();
+
+        let snapshot = room.to_snapshot();
+
+        let mut registry: BTreeMap<String, &Entity> = BTreeMap::new();
+        registry.insert(wall.name.clone(), &wall);
+        registry.insert(player.name.clone(), &player);
+
+        let rebuilt = GridRoom::from_snapshot(snapshot, &registry).unwrap();
+
+        assert_eq!(
+            rebuilt.mobile_entities.get("Player"),
+            Some(&Position { x: 0, y: 1 })
+        );
+        assert!(rebuilt.grid.get(0, 1).unwrap().is_some());
+        assert_eq!(rebuilt.mobile_entities.get("Wall"), None);
+    }
+
+    #[test]
+    fn snapshot_round_trip_preserves_mobile_footprint() {
+        let plank = Entity {
+            name: "Plank".to_owned(),
+        };
+        let footprint = [Position { x: 0, y: 0 }, Position { x: 1, y: 0 }];
+
+        let mut room = GridRoom::new(
+            "Test".to_string(),
+            Size {
+                width: 3,
+                height: 2,
+            },
+        );
+        room.add_entity(&plank, Position { x: 0, y: 0 }, false, &footprint)
+            .unwrap();
+
+        let snapshot = room.to_snapshot();
+
+        let mut registry: BTreeMap<String, &Entity> = BTreeMap::new();
+        registry.insert(plank.name.clone(), &plank);
+
+        let rebuilt = GridRoom::from_snapshot(snapshot, &registry).unwrap();
+
+        assert_eq!(
+            rebuilt.mobile_entities.get("Plank"),
+            Some(&Position { x: 0, y: 0 })
+        );
+        assert_eq!(
+            rebuilt.footprints.get("Plank"),
+            Some(&vec![Position { x: 0, y: 0 }, Position { x: 1, y: 0 }])
+        );
+        assert!(rebuilt.grid.get(0, 0).unwrap().is_some());
+        assert!(rebuilt.grid.get(0, 1).unwrap().is_some());
+    }
+
+    #[test]
+    fn import_csv_layer_places_walls_and_skips_blanks() {
+        let wall = Entity {
+            name: "Wall".to_owned(),
+        };
+
+        let mut lookup: BTreeMap<i32, &Entity> = BTreeMap::new();
+        lookup.insert(1, &wall);
+
+        let csv = "0,1,0\n1,0,1\n";
+        let room = import_csv_layer("Imported".to_string(), csv, &lookup).unwrap();
+
+        assert!(room.grid.get(0, 0).unwrap().is_none());
+        assert!(room.grid.get(0, 1).unwrap().is_some());
+        assert!(room.grid.get(1, 0).unwrap().is_some());
+        assert!(room.grid.get(1, 1).unwrap().is_none());
+    }
+}
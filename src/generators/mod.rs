@@ -0,0 +1,292 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    entity::Entity,
+    grid_room::GridRoom,
+    utils::{Position, Size, SINGLE_TILE},
+};
+
+const FILL_PROBABILITY: f64 = 0.45;
+const SMOOTHING_PASSES: usize = 4;
+
+/// Generates a cave-like room by randomly filling cells as walls, smoothing
+/// the result with a cellular automaton, and discarding any floor region
+/// that the starting open cell can't reach.
+pub fn cave<'a>(size: Size, seed: u64, wall: &'a Entity) -> GridRoom<'a> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut walls = vec![vec![false; size.width]; size.height];
+
+    for row in walls.iter_mut() {
+        for cell in row.iter_mut() {
+            *cell = rng.gen_bool(FILL_PROBABILITY);
+        }
+    }
+
+    for _ in 0..SMOOTHING_PASSES {
+        walls = smooth(&walls, size.width, size.height);
+    }
+
+    remove_disconnected_regions(&mut walls, size.width, size.height);
+
+    let mut room = GridRoom::new("Cave".to_string(), size.clone());
+    for (y, row) in walls.iter().enumerate() {
+        for (x, &is_wall) in row.iter().enumerate() {
+            if is_wall {
+                room.add_entity(wall, Position { x, y }, true, &SINGLE_TILE).unwrap();
+            }
+        }
+    }
+
+    room
+}
+
+/// Runs one pass of the cave cellular automaton: a cell becomes a wall if
+/// at least 5 of its 8 neighbors are walls, or if it has no in-bounds
+/// neighbors at all (which only happens for the single cell of a 1x1 room).
+fn smooth(walls: &[Vec<bool>], width: usize, height: usize) -> Vec<Vec<bool>> {
+    let mut next = walls.to_vec();
+
+    for (y, row) in next.iter_mut().enumerate() {
+        for (x, cell) in row.iter_mut().enumerate() {
+            let mut total_neighbors = 0;
+            let mut wall_neighbors = 0;
+
+            for (dx, dy) in neighbor_offsets() {
+                let nx = x as isize + dx;
+                let ny = y as isize + dy;
+                if nx < 0 || ny < 0 || nx as usize >= width || ny as usize >= height {
+                    continue;
+                }
+
+                total_neighbors += 1;
+                if walls[ny as usize][nx as usize] {
+                    wall_neighbors += 1;
+                }
+            }
+
+            *cell = total_neighbors == 0 || wall_neighbors >= 5;
+        }
+    }
+
+    next
+}
+
+fn neighbor_offsets() -> [(isize, isize); 8] {
+    [
+        (-1, -1),
+        (0, -1),
+        (1, -1),
+        (-1, 0),
+        (1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+    ]
+}
+
+/// Flood-fills from the first open cell found and turns every open cell it
+/// can't reach back into a wall, so the generated cave has no isolated
+/// pockets the player could never get to.
+fn remove_disconnected_regions(walls: &mut [Vec<bool>], width: usize, height: usize) {
+    let start = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .find(|&(x, y)| !walls[y][x]);
+
+    let Some(start) = start else {
+        return;
+    };
+
+    let mut reachable = vec![vec![false; width]; height];
+    let mut stack = vec![start];
+    reachable[start.1][start.0] = true;
+
+    while let Some((cx, cy)) = stack.pop() {
+        for (dx, dy) in [(-1isize, 0isize), (1, 0), (0, -1), (0, 1)] {
+            let nx = cx as isize + dx;
+            let ny = cy as isize + dy;
+            if nx < 0 || ny < 0 || nx as usize >= width || ny as usize >= height {
+                continue;
+            }
+
+            let (nx, ny) = (nx as usize, ny as usize);
+            if !walls[ny][nx] && !reachable[ny][nx] {
+                reachable[ny][nx] = true;
+                stack.push((nx, ny));
+            }
+        }
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            if !walls[y][x] && !reachable[y][x] {
+                walls[y][x] = true;
+            }
+        }
+    }
+}
+
+/// Generates a maze by randomized depth-first backtracking over a cell grid
+/// half the resolution of `size` (cell `(cx, cy)` sits on tile
+/// `(2 * cx, 2 * cy)`), carving one-tile-wide passages between adjacent
+/// cells.
+pub fn maze<'a>(size: Size, seed: u64, wall: &'a Entity) -> GridRoom<'a> {
+    let mut room = GridRoom::new("Maze".to_string(), size.clone());
+    for y in 0..size.height {
+        for x in 0..size.width {
+            room.add_entity(wall, Position { x, y }, true, &SINGLE_TILE).unwrap();
+        }
+    }
+
+    let cell_cols = size.width.div_ceil(2);
+    let cell_rows = size.height.div_ceil(2);
+    if cell_cols == 0 || cell_rows == 0 {
+        return room;
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut visited = vec![vec![false; cell_cols]; cell_rows];
+    let mut stack = vec![(0usize, 0usize)];
+    visited[0][0] = true;
+    carve(&mut room, &Position { x: 0, y: 0 });
+
+    while let Some(&(cx, cy)) = stack.last() {
+        let neighbors: Vec<(usize, usize)> = [(-1isize, 0isize), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let nx = cx as isize + dx;
+                let ny = cy as isize + dy;
+                if nx < 0 || ny < 0 || nx as usize >= cell_cols || ny as usize >= cell_rows {
+                    return None;
+                }
+
+                let (nx, ny) = (nx as usize, ny as usize);
+                (!visited[ny][nx]).then_some((nx, ny))
+            })
+            .collect();
+
+        if neighbors.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        let (nx, ny) = neighbors[rng.gen_range(0..neighbors.len())];
+        visited[ny][nx] = true;
+
+        carve(&mut room, &Position { x: cx + nx, y: cy + ny });
+        carve(&mut room, &Position { x: nx * 2, y: ny * 2 });
+
+        stack.push((nx, ny));
+    }
+
+    room
+}
+
+fn carve(room: &mut GridRoom, position: &Position) {
+    if let Some(cell) = room.grid.get_mut(position.y, position.x) {
+        *cell = None;
+    }
+}
+
+#[cfg(test)]
+mod generators_tests {
+    use crate::{entity::Entity, utils::Size};
+
+    use super::{cave, maze};
+
+    /// Flood-fills the room's open (non-wall) cells starting from the
+    /// first one found and asserts every open cell was reached, i.e. the
+    /// room has exactly one connected open region.
+    fn assert_fully_connected(room: &crate::grid_room::GridRoom) {
+        let width = room.grid.cols();
+        let height = room.grid.rows();
+
+        let open_cells: Vec<(usize, usize)> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|&(x, y)| room.grid.get(y, x).unwrap().is_none())
+            .collect();
+
+        let Some(&start) = open_cells.first() else {
+            return;
+        };
+
+        let mut reachable = vec![vec![false; width]; height];
+        let mut stack = vec![start];
+        reachable[start.1][start.0] = true;
+
+        while let Some((cx, cy)) = stack.pop() {
+            for (dx, dy) in [(-1isize, 0isize), (1, 0), (0, -1), (0, 1)] {
+                let nx = cx as isize + dx;
+                let ny = cy as isize + dy;
+                if nx < 0 || ny < 0 || nx as usize >= width || ny as usize >= height {
+                    continue;
+                }
+
+                let (nx, ny) = (nx as usize, ny as usize);
+                if room.grid.get(ny, nx).unwrap().is_none() && !reachable[ny][nx] {
+                    reachable[ny][nx] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+
+        for (x, y) in open_cells {
+            assert!(reachable[y][x], "cell ({x}, {y}) is not reachable from the rest of the room");
+        }
+    }
+
+    #[test]
+    fn cave_has_no_disconnected_regions() {
+        let wall = Entity {
+            name: "Wall".to_owned(),
+        };
+
+        let room = cave(
+            Size {
+                width: 20,
+                height: 20,
+            },
+            42,
+            &wall,
+        );
+
+        assert_fully_connected(&room);
+    }
+
+    #[test]
+    fn maze_is_fully_connected() {
+        let wall = Entity {
+            name: "Wall".to_owned(),
+        };
+
+        let room = maze(
+            Size {
+                width: 9,
+                height: 9,
+            },
+            7,
+            &wall,
+        );
+
+        assert_fully_connected(&room);
+    }
+
+    #[test]
+    fn maze_does_not_panic_on_degenerate_sizes() {
+        let wall = Entity {
+            name: "Wall".to_owned(),
+        };
+
+        for size in [
+            Size {
+                width: 1,
+                height: 1,
+            },
+            Size {
+                width: 0,
+                height: 0,
+            },
+        ] {
+            maze(size, 1, &wall);
+        }
+    }
+}
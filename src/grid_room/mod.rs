@@ -1,18 +1,54 @@
-use std::collections::BTreeMap;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashSet};
 
 use grid::Grid;
 
 use crate::{
     entity::Entity,
     error::MapError,
-    utils::{MoveResult, Movement, Position, Size},
+    utils::{Direction, MoveResult, Movement, Position, Size},
 };
 
+/// Per-octant `[xx, xy, yx, yy]` multipliers that transform the local
+/// `(row, col)` coordinates used by [`GridRoom::cast_light`] into map-space
+/// offsets from the origin.
+const OCTANTS: [[isize; 4]; 8] = [
+    [1, 0, 0, 1],
+    [0, 1, 1, 0],
+    [0, -1, 1, 0],
+    [-1, 0, 0, 1],
+    [-1, 0, 0, -1],
+    [0, -1, -1, 0],
+    [0, 1, -1, 0],
+    [1, 0, 0, -1],
+];
+
+/// Steps one tile from `position` in `direction`, returning `None` if that
+/// would underflow an unsigned coordinate (the caller still needs to check
+/// the result against the grid's actual bounds).
+fn step(position: &Position, direction: &Direction) -> Option<Position> {
+    match direction {
+        Direction::Up => Some(Position {
+            x: position.x,
+            y: position.y + 1,
+        }),
+        Direction::Down => position.y.checked_sub(1).map(|y| Position { x: position.x, y }),
+        Direction::Left => position.x.checked_sub(1).map(|x| Position { x, y: position.y }),
+        Direction::Right => Some(Position {
+            x: position.x + 1,
+            y: position.y,
+        }),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GridRoom<'a> {
     pub name: String,
     pub grid: Grid<Option<&'a Entity>>,
     pub mobile_entities: BTreeMap<String, Position>,
+    /// Footprint of each mobile entity, as cell offsets from its tracked
+    /// position. A single-tile entity has the footprint `[Position { x: 0, y: 0 }]`.
+    pub footprints: BTreeMap<String, Vec<Position>>,
 }
 
 impl<'a> GridRoom<'a> {
@@ -21,6 +57,7 @@ impl<'a> GridRoom<'a> {
             name,
             grid: Grid::new(size.height, size.width),
             mobile_entities: BTreeMap::new(),
+            footprints: BTreeMap::new(),
         }
     }
 
@@ -29,16 +66,20 @@ impl<'a> GridRoom<'a> {
         entity: &'a Entity,
         position: Position,
         is_static: bool,
+        footprint: &[Position],
     ) -> Result<(), MapError> {
-        match self.grid.get_mut(position.y, position.x) {
-            Some(cell) => {
-                *cell = Some(entity);
+        let cells = self.footprint_cells(&position, footprint)?;
+
+        for cell in &cells {
+            if let Some(slot) = self.grid.get_mut(cell.y, cell.x) {
+                *slot = Some(entity);
             }
-            None => return Err(MapError::OutOfBounds),
-        };
+        }
 
         if !is_static {
             self.mobile_entities.insert(entity.name.clone(), position);
+            self.footprints
+                .insert(entity.name.clone(), footprint.to_vec());
         }
 
         Ok(())
@@ -48,111 +89,358 @@ impl<'a> GridRoom<'a> {
         &mut self,
         entity: &'a Entity,
         movement: Movement,
-    ) -> Result<MoveResult, MapError> {
-        let self_clone = self.clone();
-        let start = self_clone
+    ) -> Result<MoveResult<'a>, MapError> {
+        let start = self
             .mobile_entities
             .get(&entity.name)
+            .cloned()
             .ok_or(MapError::OutOfBounds)?;
+        let footprint = self
+            .footprints
+            .get(&entity.name)
+            .cloned()
+            .unwrap_or_else(|| vec![Position { x: 0, y: 0 }]);
+        let own_cells = self.footprint_cells(&start, &footprint)?;
 
-        let (end, traversed_tiles) = match movement.direction {
-            crate::utils::Direction::Up => (
-                Position {
-                    x: start.x,
-                    y: start.y + movement.distance,
-                },
-                (start.y..start.y + movement.distance)
-                    .map(|y| Position {
-                        x: start.x,
-                        y: y + 1,
-                    })
-                    .collect::<Vec<Position>>(),
-            ),
-            crate::utils::Direction::Down => (
-                Position {
-                    x: start.x,
-                    y: start.y - movement.distance,
-                },
-                ((start.y - movement.distance)..start.y)
-                    .rev()
-                    .map(|y| Position { x: start.x, y })
-                    .collect(),
-            ),
-            crate::utils::Direction::Left => (
-                Position {
-                    x: start.x - movement.distance,
-                    y: start.y,
-                },
-                ((start.x - movement.distance)..start.x)
-                    .rev()
-                    .map(|x| Position { x, y: start.y })
-                    .collect(),
-            ),
-            crate::utils::Direction::Right => (
-                Position {
-                    x: start.x + movement.distance,
-                    y: start.y,
-                },
-                (start.x..start.y + movement.distance)
-                    .map(|x| Position {
-                        x: x + 1,
-                        y: start.y,
-                    })
-                    .collect(),
-            ),
-        };
+        let mut settled = start.clone();
 
-        // println!("Start: {:#?}", start);
-        // println!("End: {:#?}", end);
-        // println!("Traversed tiles: {:#?}", traversed_tiles);
-
-        for tile_index in 0..(traversed_tiles.len()) {
-            let tile = &traversed_tiles[tile_index];
-            let cloned_self = self.clone();
-            match cloned_self.grid.get(tile.y, tile.x) {
-                Some(cell) => {
-                    if cell.is_some() {
-                        println!("Collision!");
-                        let resolved_tile = &traversed_tiles[tile_index - 1];
-                        self.swap(start, resolved_tile)?;
-                        return Ok(MoveResult::Collision(vec![entity, cell.unwrap()]));
-                    }
+        for _ in 0..movement.distance {
+            let Some(candidate) = step(&settled, &movement.direction) else {
+                return self.stop_short(entity, &start, &settled, &footprint, MoveResult::Failure);
+            };
+
+            let target_cells = match self.footprint_cells(&candidate, &footprint) {
+                Ok(cells) => cells,
+                Err(_) => {
+                    return self.stop_short(
+                        entity,
+                        &start,
+                        &settled,
+                        &footprint,
+                        MoveResult::Failure,
+                    );
+                }
+            };
+
+            let mut blockers: Vec<&'a Entity> = Vec::new();
+            for cell in &target_cells {
+                if own_cells.contains(cell) {
+                    continue;
                 }
-                None => {
-                    if tile_index == 0 {
-                        return Err(MapError::OutOfBounds);
-                    } else {
-                        let resolved_tile = &traversed_tiles[tile_index - 1];
-                        self.swap(start, resolved_tile)?;
-                        return Ok(MoveResult::Failure);
+                if let Some(Some(occupant)) = self.grid.get(cell.y, cell.x) {
+                    if !blockers.iter().any(|blocker| blocker.name == occupant.name) {
+                        blockers.push(occupant);
                     }
                 }
+            }
+
+            if !blockers.is_empty() {
+                return self.stop_short(
+                    entity,
+                    &start,
+                    &settled,
+                    &footprint,
+                    MoveResult::Collision(blockers),
+                );
+            }
+
+            settled = candidate;
+        }
+
+        self.relocate(entity, &start, &settled, &footprint)?;
+        Ok(MoveResult::Success)
+    }
+
+    /// Moves `entity` from `start` to `settled` (which may be `start`
+    /// itself, meaning no movement happened at all) and returns `result`,
+    /// erroring instead if the entity never moved and the obstruction was
+    /// the map's own edge.
+    fn stop_short(
+        &mut self,
+        entity: &'a Entity,
+        start: &Position,
+        settled: &Position,
+        footprint: &[Position],
+        result: MoveResult<'a>,
+    ) -> Result<MoveResult<'a>, MapError> {
+        if settled == start {
+            if let MoveResult::Failure = result {
+                return Err(MapError::OutOfBounds);
+            }
+        }
+
+        self.relocate(entity, start, settled, footprint)?;
+        Ok(result)
+    }
+
+    /// Computes the absolute cells `footprint` covers when anchored at
+    /// `position`, erroring if any of them fall outside the grid.
+    fn footprint_cells(
+        &self,
+        position: &Position,
+        footprint: &[Position],
+    ) -> Result<Vec<Position>, MapError> {
+        footprint
+            .iter()
+            .map(|offset| {
+                let cell = Position {
+                    x: position.x + offset.x,
+                    y: position.y + offset.y,
+                };
+                if self.grid.get(cell.y, cell.x).is_some() {
+                    Ok(cell)
+                } else {
+                    Err(MapError::OutOfBounds)
+                }
+            })
+            .collect()
+    }
+
+    /// Finds the shortest walkable path from `from` to `to` using A* over
+    /// the 4-neighborhood, treating any occupied cell (other than the
+    /// destination itself) as blocked. Returns `None` if no path exists.
+    pub fn path_to(&self, from: &Position, to: &Position) -> Option<Vec<Position>> {
+        let in_bounds = |position: &Position| self.grid.get(position.y, position.x).is_some();
+        let is_blocked =
+            |position: &Position| matches!(self.grid.get(position.y, position.x), Some(Some(_)));
+        let heuristic = |position: &Position| position.x.abs_diff(to.x) + position.y.abs_diff(to.y);
+
+        let mut open_set = BinaryHeap::new();
+        open_set.push(Reverse((heuristic(from), from.clone())));
+
+        let mut came_from: BTreeMap<Position, Position> = BTreeMap::new();
+        let mut g_score: BTreeMap<Position, usize> = BTreeMap::new();
+        g_score.insert(from.clone(), 0);
+
+        while let Some(Reverse((_, current))) = open_set.pop() {
+            if current == *to {
+                let mut path = vec![current.clone()];
+                let mut node = current;
+                while let Some(previous) = came_from.get(&node) {
+                    path.push(previous.clone());
+                    node = previous.clone();
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for direction in [
+                Direction::Up,
+                Direction::Down,
+                Direction::Left,
+                Direction::Right,
+            ] {
+                let Some(neighbor) = step(&current, &direction) else {
+                    continue;
+                };
+
+                if !in_bounds(&neighbor) || (neighbor != *to && is_blocked(&neighbor)) {
+                    continue;
+                }
+
+                let tentative_g = g_score[&current] + 1;
+                if tentative_g < *g_score.get(&neighbor).unwrap_or(&usize::MAX) {
+                    came_from.insert(neighbor.clone(), current.clone());
+                    g_score.insert(neighbor.clone(), tentative_g);
+                    open_set.push(Reverse((tentative_g + heuristic(&neighbor), neighbor)));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Computes the set of cells visible from `origin` within `radius`
+    /// tiles, using recursive shadowcasting over the eight octants.
+    /// Occupied cells block line of sight but are themselves visible.
+    pub fn visible_cells(&self, origin: &Position, radius: usize) -> HashSet<Position> {
+        let mut visible = HashSet::new();
+        visible.insert(origin.clone());
+
+        for octant in OCTANTS {
+            self.cast_light(origin, 1, 1.0, 0.0, radius as isize, octant, &mut visible);
+        }
+
+        visible
+    }
+
+    /// Scans one octant row by row, narrowing `[start_slope, end_slope]` as
+    /// it goes and recursing beneath each run of opaque cells it crosses in a
+    /// row.
+    #[allow(clippy::too_many_arguments)]
+    fn cast_light(
+        &self,
+        origin: &Position,
+        row: isize,
+        mut start_slope: f64,
+        end_slope: f64,
+        radius: isize,
+        [xx, xy, yx, yy]: [isize; 4],
+        visible: &mut HashSet<Position>,
+    ) {
+        if start_slope < end_slope || row > radius {
+            return;
+        }
+
+        let mut blocked = false;
+        let mut next_start_slope = start_slope;
+
+        for col in (0..=row).rev() {
+            let dx = row * xx + col * xy;
+            let dy = row * yx + col * yy;
+
+            let left_slope = (col as f64 + 0.5) / (row as f64 - 0.5);
+            let right_slope = (col as f64 - 0.5) / (row as f64 + 0.5);
+
+            if right_slope > start_slope {
+                continue;
+            }
+            if left_slope < end_slope {
+                break;
+            }
+
+            let map_x = origin.x as isize + dx;
+            let map_y = origin.y as isize + dy;
+            if map_x < 0 || map_y < 0 {
+                continue;
+            }
+            let position = Position {
+                x: map_x as usize,
+                y: map_y as usize,
             };
+
+            let cell = self.grid.get(position.y, position.x);
+            if (dx * dx + dy * dy) <= radius * radius && cell.is_some() {
+                visible.insert(position.clone());
+            }
+
+            let opaque = matches!(cell, Some(Some(_)));
+
+            if blocked {
+                if opaque {
+                    next_start_slope = right_slope;
+                    continue;
+                }
+                blocked = false;
+                start_slope = next_start_slope;
+            } else if opaque && row < radius {
+                blocked = true;
+                next_start_slope = right_slope;
+                self.cast_light(
+                    origin,
+                    row + 1,
+                    start_slope,
+                    left_slope,
+                    radius,
+                    [xx, xy, yx, yy],
+                    visible,
+                );
+            }
         }
 
-        self.swap(start, &end)?;
+        if !blocked {
+            self.cast_light(
+                origin,
+                row + 1,
+                start_slope,
+                end_slope,
+                radius,
+                [xx, xy, yx, yy],
+                visible,
+            );
+        }
+    }
 
-        Ok(MoveResult::Success)
+    /// Slides every mobile entity as far as it can go in `direction`, like
+    /// gravity tilting the whole board at once. Entities closest to the
+    /// edge they're heading towards are processed first, so they settle
+    /// and become barriers for the entities behind them. Returns the
+    /// entities that actually moved, along with their new positions.
+    pub fn tilt(&mut self, direction: Direction) -> Vec<(String, Position)> {
+        let mut entities: Vec<(String, Position)> = self
+            .mobile_entities
+            .iter()
+            .map(|(name, position)| (name.clone(), position.clone()))
+            .collect();
+
+        match direction {
+            Direction::Up => entities.sort_by_key(|entity| Reverse(entity.1.y)),
+            Direction::Down => entities.sort_by_key(|entity| entity.1.y),
+            Direction::Left => entities.sort_by_key(|entity| entity.1.x),
+            Direction::Right => entities.sort_by_key(|entity| Reverse(entity.1.x)),
+        }
+
+        let mut moved = Vec::new();
+
+        for (name, start) in entities {
+            let footprint = self
+                .footprints
+                .get(&name)
+                .cloned()
+                .unwrap_or_else(|| vec![Position { x: 0, y: 0 }]);
+            let Ok(own_cells) = self.footprint_cells(&start, &footprint) else {
+                continue;
+            };
+
+            let mut settled = start.clone();
+            while let Some(candidate) = step(&settled, &direction) {
+                let Ok(target_cells) = self.footprint_cells(&candidate, &footprint) else {
+                    break;
+                };
+
+                let blocked = target_cells.iter().any(|cell| {
+                    !own_cells.contains(cell)
+                        && matches!(self.grid.get(cell.y, cell.x), Some(Some(_)))
+                });
+
+                if blocked {
+                    break;
+                }
+
+                settled = candidate;
+            }
+
+            if settled == start {
+                continue;
+            }
+
+            let entity = self.grid.get(start.y, start.x).unwrap().unwrap();
+            self.relocate(entity, &start, &settled, &footprint)
+                .expect("start and settled were already validated as in-bounds");
+
+            moved.push((name, settled));
+        }
+
+        moved
     }
 
-    fn swap(&mut self, start: &Position, end: &Position) -> Result<(), MapError> {
-        let entity = self.grid.get(start.y, start.x).unwrap().unwrap();
-        match self.grid.get_mut(end.y, end.x) {
-            Some(cell) => {
-                *cell = Some(entity);
+    /// Clears `entity`'s footprint at `from` and writes it at `to`,
+    /// atomically with respect to the grid (no cell is ever briefly shared
+    /// between the old and new footprint).
+    fn relocate(
+        &mut self,
+        entity: &'a Entity,
+        from: &Position,
+        to: &Position,
+        footprint: &[Position],
+    ) -> Result<(), MapError> {
+        let old_cells = self.footprint_cells(from, footprint)?;
+        let new_cells = self.footprint_cells(to, footprint)?;
+
+        for cell in &old_cells {
+            if let Some(slot) = self.grid.get_mut(cell.y, cell.x) {
+                *slot = None;
             }
-            None => return Err(MapError::OutOfBounds),
-        };
+        }
 
-        match self.grid.get_mut(start.y, start.x) {
-            Some(cell) => {
-                *cell = None;
+        for cell in &new_cells {
+            if let Some(slot) = self.grid.get_mut(cell.y, cell.x) {
+                *slot = Some(entity);
             }
-            None => return Err(MapError::OutOfBounds),
-        };
+        }
 
         self.mobile_entities
-            .insert(entity.name.clone(), end.to_owned());
+            .insert(entity.name.clone(), to.to_owned());
 
         Ok(())
     }
@@ -162,7 +450,7 @@ impl<'a> GridRoom<'a> {
 mod grid_room_tests {
     use crate::{
         entity::Entity,
-        utils::{Direction, Movement, Position, Size},
+        utils::{Direction, MoveResult, Movement, Position, Size, SINGLE_TILE},
     };
 
     use super::GridRoom;
@@ -193,7 +481,7 @@ mod grid_room_tests {
             name: "Test".to_owned(),
         };
 
-        grid.add_entity(&entity, Position { x: 1, y: 1 }, false)
+        grid.add_entity(&entity, Position { x: 1, y: 1 }, false, &SINGLE_TILE)
             .unwrap();
         println!("{:#?}", grid)
     }
@@ -212,7 +500,7 @@ mod grid_room_tests {
             name: "Test".to_owned(),
         };
 
-        grid.add_entity(&entity, Position { x: 2, y: 2 }, false)
+        grid.add_entity(&entity, Position { x: 2, y: 2 }, false, &SINGLE_TILE)
             .unwrap();
 
         grid.move_entity(
@@ -250,7 +538,7 @@ mod grid_room_tests {
             name: "Test".to_owned(),
         };
 
-        grid.add_entity(&entity, Position { x: 2, y: 2 }, false)
+        grid.add_entity(&entity, Position { x: 2, y: 2 }, false, &SINGLE_TILE)
             .unwrap();
 
         let result = grid
@@ -280,14 +568,14 @@ mod grid_room_tests {
             name: "Bread Bandit".to_owned(),
         };
 
-        grid.add_entity(&wall, Position { x: 2, y: 4 }, false)
+        grid.add_entity(&wall, Position { x: 2, y: 4 }, false, &SINGLE_TILE)
             .unwrap();
 
         let entity = Entity {
             name: "Bread Cowboy".to_owned(),
         };
 
-        grid.add_entity(&entity, Position { x: 2, y: 2 }, false)
+        grid.add_entity(&entity, Position { x: 2, y: 2 }, false, &SINGLE_TILE)
             .unwrap();
         println!("{:#?}", grid);
 
@@ -303,4 +591,257 @@ mod grid_room_tests {
         println!("{:#?}", result);
         println!("{:#?}", grid.grid);
     }
+
+    #[test]
+    fn path_to_around_wall() {
+        let mut grid = GridRoom::new(
+            "Test".to_string(),
+            Size {
+                width: 5,
+                height: 5,
+            },
+        );
+
+        let wall = Entity {
+            name: "Bread Bandit".to_owned(),
+        };
+
+        for y in 0..4 {
+            grid.add_entity(&wall, Position { x: 2, y }, true, &SINGLE_TILE).unwrap();
+        }
+
+        let path = grid
+            .path_to(&Position { x: 0, y: 0 }, &Position { x: 4, y: 0 })
+            .unwrap();
+
+        assert_eq!(path.first(), Some(&Position { x: 0, y: 0 }));
+        assert_eq!(path.last(), Some(&Position { x: 4, y: 0 }));
+        assert!(path.iter().all(|position| !(position.x == 2 && position.y < 4)));
+    }
+
+    #[test]
+    fn path_to_unreachable() {
+        let mut grid = GridRoom::new(
+            "Test".to_string(),
+            Size {
+                width: 5,
+                height: 5,
+            },
+        );
+
+        let wall = Entity {
+            name: "Bread Bandit".to_owned(),
+        };
+
+        for y in 0..5 {
+            grid.add_entity(&wall, Position { x: 2, y }, true, &SINGLE_TILE).unwrap();
+        }
+
+        let path = grid.path_to(&Position { x: 0, y: 0 }, &Position { x: 4, y: 0 });
+        assert!(path.is_none());
+    }
+
+    #[test]
+    fn visible_cells_open_room() {
+        let grid = GridRoom::new(
+            "Test".to_string(),
+            Size {
+                width: 5,
+                height: 5,
+            },
+        );
+
+        let visible = grid.visible_cells(&Position { x: 2, y: 2 }, 2);
+        assert!(visible.contains(&Position { x: 2, y: 2 }));
+        assert!(visible.contains(&Position { x: 0, y: 2 }));
+        assert!(visible.contains(&Position { x: 4, y: 2 }));
+    }
+
+    #[test]
+    fn visible_cells_blocked_by_wall() {
+        let mut grid = GridRoom::new(
+            "Test".to_string(),
+            Size {
+                width: 5,
+                height: 5,
+            },
+        );
+
+        let wall = Entity {
+            name: "Bread Bandit".to_owned(),
+        };
+
+        grid.add_entity(&wall, Position { x: 3, y: 2 }, true, &SINGLE_TILE)
+            .unwrap();
+
+        let visible = grid.visible_cells(&Position { x: 2, y: 2 }, 2);
+        assert!(visible.contains(&Position { x: 3, y: 2 }));
+        assert!(!visible.contains(&Position { x: 4, y: 2 }));
+    }
+
+    #[test]
+    fn visible_cells_wall_does_not_darken_off_axis_cells() {
+        let mut grid = GridRoom::new(
+            "Test".to_string(),
+            Size {
+                width: 13,
+                height: 13,
+            },
+        );
+
+        let wall = Entity {
+            name: "Bread Bandit".to_owned(),
+        };
+
+        grid.add_entity(&wall, Position { x: 8, y: 6 }, true, &SINGLE_TILE)
+            .unwrap();
+
+        let visible = grid.visible_cells(&Position { x: 6, y: 6 }, 5);
+        assert!(visible.contains(&Position { x: 9, y: 4 }));
+        assert!(visible.contains(&Position { x: 10, y: 3 }));
+        assert!(!visible.contains(&Position { x: 10, y: 6 }));
+    }
+
+    #[test]
+    fn tilt_slides_entities_until_blocked() {
+        let mut grid = GridRoom::new(
+            "Test".to_string(),
+            Size {
+                width: 5,
+                height: 5,
+            },
+        );
+
+        let a = Entity {
+            name: "A".to_owned(),
+        };
+        let b = Entity {
+            name: "B".to_owned(),
+        };
+
+        grid.add_entity(&a, Position { x: 2, y: 0 }, false, &SINGLE_TILE).unwrap();
+        grid.add_entity(&b, Position { x: 2, y: 2 }, false, &SINGLE_TILE).unwrap();
+
+        let moved = grid.tilt(Direction::Up);
+
+        assert_eq!(grid.mobile_entities.get("A"), Some(&Position { x: 2, y: 3 }));
+        assert_eq!(grid.mobile_entities.get("B"), Some(&Position { x: 2, y: 4 }));
+        assert_eq!(moved.len(), 2);
+    }
+
+    #[test]
+    fn tilt_slides_multi_tile_footprint_and_clears_old_cells() {
+        let mut grid = GridRoom::new(
+            "Test".to_string(),
+            Size {
+                width: 5,
+                height: 5,
+            },
+        );
+
+        let plank = Entity {
+            name: "Plank".to_owned(),
+        };
+        let footprint = [Position { x: 0, y: 0 }, Position { x: 1, y: 0 }];
+
+        grid.add_entity(&plank, Position { x: 2, y: 2 }, false, &footprint)
+            .unwrap();
+
+        let moved = grid.tilt(Direction::Right);
+
+        assert_eq!(moved.len(), 1);
+        assert_eq!(
+            grid.mobile_entities.get("Plank"),
+            Some(&Position { x: 3, y: 2 })
+        );
+        assert!(grid.grid.get(2, 2).unwrap().is_none());
+        assert!(grid.grid.get(2, 3).unwrap().is_some());
+        assert!(grid.grid.get(2, 4).unwrap().is_some());
+    }
+
+    #[test]
+    fn add_entity_with_footprint_covers_every_cell() {
+        let mut grid = GridRoom::new(
+            "Test".to_string(),
+            Size {
+                width: 5,
+                height: 5,
+            },
+        );
+
+        let entity = Entity {
+            name: "Crate".to_owned(),
+        };
+
+        let footprint = [
+            Position { x: 0, y: 0 },
+            Position { x: 1, y: 0 },
+            Position { x: 0, y: 1 },
+            Position { x: 1, y: 1 },
+        ];
+
+        grid.add_entity(&entity, Position { x: 1, y: 1 }, false, &footprint)
+            .unwrap();
+
+        for offset in &footprint {
+            assert!(grid
+                .grid
+                .get(1 + offset.y, 1 + offset.x)
+                .unwrap()
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn move_entity_with_footprint_collides_on_leading_edge() {
+        let mut grid = GridRoom::new(
+            "Test".to_string(),
+            Size {
+                width: 5,
+                height: 5,
+            },
+        );
+
+        let footprint = [
+            Position { x: 0, y: 0 },
+            Position { x: 1, y: 0 },
+            Position { x: 0, y: 1 },
+            Position { x: 1, y: 1 },
+        ];
+
+        let wall = Entity {
+            name: "Bread Bandit".to_owned(),
+        };
+        grid.add_entity(&wall, Position { x: 3, y: 1 }, true, &SINGLE_TILE)
+            .unwrap();
+
+        let crate_entity = Entity {
+            name: "Crate".to_owned(),
+        };
+        grid.add_entity(&crate_entity, Position { x: 0, y: 1 }, false, &footprint)
+            .unwrap();
+
+        let result = grid
+            .move_entity(
+                &crate_entity,
+                Movement {
+                    distance: 3,
+                    direction: Direction::Right,
+                },
+            )
+            .unwrap();
+
+        match result {
+            MoveResult::Collision(entities) => {
+                assert_eq!(entities.len(), 1);
+                assert_eq!(entities[0].name, "Bread Bandit");
+            }
+            other => panic!("expected a collision, got {other:?}"),
+        }
+
+        assert_eq!(
+            grid.mobile_entities.get("Crate"),
+            Some(&Position { x: 1, y: 1 })
+        );
+    }
 }
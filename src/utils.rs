@@ -1,17 +1,22 @@
+use serde::{Deserialize, Serialize};
+
 use crate::entity::Entity;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Size {
     pub width: usize,
     pub height: usize,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Position {
     pub x: usize,
     pub y: usize,
 }
 
+/// Footprint for an entity that occupies a single grid cell.
+pub const SINGLE_TILE: [Position; 1] = [Position { x: 0, y: 0 }];
+
 #[derive(Debug, Clone)]
 pub enum Direction {
     Up,